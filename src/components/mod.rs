@@ -0,0 +1,5 @@
+mod hero;
+pub use hero::Hero;
+
+mod echo;
+pub use echo::Echo;
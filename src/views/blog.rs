@@ -0,0 +1,26 @@
+use dioxus::prelude::*;
+
+use crate::Route;
+
+#[component]
+pub fn Blog(id: i32) -> Element {
+    rsx! {
+        document::Title { "Blog Post {id}" }
+        document::Meta { property: "og:title", content: "Blog Post {id}" }
+
+        div {
+            id: "blog",
+            h1 { "This is blog post {id}!" }
+            p { "In Dioxus, we use the URL to determine what to render." }
+            Link {
+                to: Route::Blog { id: id - 1 },
+                "Previous"
+            }
+            span { " <---> " }
+            Link {
+                to: Route::Blog { id: id + 1 },
+                "Next"
+            }
+        }
+    }
+}
@@ -5,15 +5,23 @@ use dioxus::prelude::*;
 #[component]
 pub fn Home() -> Element {
     rsx! {
+        document::Title { "Application Review - Home" }
+
         Hero {}
         Echo {}
-        div { 
-            style: "text-align: center; margin: 2rem;",
-            Link { 
-                to: "/review", 
+        div {
+            style: "text-align: center; margin: 2rem; display: flex; gap: 1rem; justify-content: center;",
+            Link {
+                to: "/review",
                 class: "nav-link",
                 style: "background: #007bff; color: white; padding: 1rem 2rem; text-decoration: none; border-radius: 5px; font-size: 1.1rem;",
-                "Start Application Review" 
+                "Start Application Review"
+            }
+            Link {
+                to: "/results",
+                class: "nav-link",
+                style: "background: #6c757d; color: white; padding: 1rem 2rem; text-decoration: none; border-radius: 5px; font-size: 1.1rem;",
+                "View Results"
             }
         }
     }
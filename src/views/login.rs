@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+use crate::{current_reviewer, login, reviewer_session, Route};
+
+#[component]
+pub fn Login() -> Element {
+    let mut reviewer_id = use_signal(|| String::new());
+    let mut password = use_signal(|| String::new());
+    let mut error = use_signal(|| String::new());
+    let mut reviewer = reviewer_session();
+    let nav = use_navigator();
+
+    let handle_login = move |_| {
+        let reviewer_id = reviewer_id();
+        let password = password();
+        spawn(async move {
+            match login(reviewer_id, password).await {
+                Ok(_) => {
+                    // Refresh the shared session signal so `Navbar` immediately reflects the
+                    // signed-in reviewer instead of waiting for a remount/reload.
+                    if let Ok(current) = current_reviewer().await {
+                        reviewer.set(current);
+                    }
+                    nav.push(Route::ReviewPage {});
+                }
+                Err(e) => {
+                    error.set(e.to_string());
+                }
+            }
+        });
+    };
+
+    rsx! {
+        div { class: "login-container",
+            h1 { "Reviewer Login" }
+
+            if !error().is_empty() {
+                div { class: "status-message", "{error()}" }
+            }
+
+            form {
+                onsubmit: handle_login,
+
+                label { "Reviewer ID:" }
+                input {
+                    value: "{reviewer_id}",
+                    oninput: move |event| reviewer_id.set(event.value()),
+                }
+
+                label { "Password:" }
+                input {
+                    r#type: "password",
+                    value: "{password}",
+                    oninput: move |event| password.set(event.value()),
+                }
+
+                button { r#type: "submit", class: "submit-btn", "Log in" }
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+mod home;
+pub use home::Home;
+
+mod blog;
+pub use blog::Blog;
+
+mod navbar;
+pub use navbar::Navbar;
+
+mod login;
+pub use login::Login;
+
+mod results;
+pub use results::ResultsPage;
+
+mod review_thanks;
+pub use review_thanks::ReviewThanks;
@@ -0,0 +1,47 @@
+use dioxus::prelude::*;
+
+use crate::{current_reviewer, logout, reviewer_session, Route};
+
+#[component]
+pub fn Navbar() -> Element {
+    let mut reviewer = reviewer_session();
+
+    // The navbar is the shared route layout and only mounts once, so this only covers the
+    // initial page load; `Login` updates `reviewer` directly on successful sign-in.
+    use_effect(move || {
+        spawn(async move {
+            if let Ok(current) = current_reviewer().await {
+                reviewer.set(current);
+            }
+        });
+    });
+
+    let handle_logout = move |_| {
+        spawn(async move {
+            if logout().await.is_ok() {
+                reviewer.set(None);
+            }
+        });
+    };
+
+    rsx! {
+        div {
+            id: "navbar",
+            Link { to: Route::Home {}, "Home" }
+            Link { to: Route::Blog { id: 1 }, "Blog" }
+            Link { to: Route::ReviewPage {}, "Review" }
+
+            match reviewer() {
+                Some(reviewer) => rsx! {
+                    span { class: "reviewer-name", "Signed in as {reviewer.reviewer_id}" }
+                    button { class: "logout-btn", onclick: handle_logout, "Log out" }
+                },
+                None => rsx! {
+                    Link { to: Route::Login {}, "Log in" }
+                },
+            }
+        }
+
+        Outlet::<Route> {}
+    }
+}
@@ -0,0 +1,60 @@
+use dioxus::prelude::*;
+
+use crate::{load_results, StarRating};
+
+#[component]
+pub fn ResultsPage() -> Element {
+    rsx! {
+        document::Title { "Application Review - Results" }
+
+        SuspenseBoundary {
+            fallback: |_| rsx! { div { class: "loading", "Loading results..." } },
+            ResultsView {}
+        }
+    }
+}
+
+#[component]
+fn ResultsView() -> Element {
+    let results = use_resource(|| load_results());
+    let loaded_results = results.suspend()?;
+
+    rsx! {
+        div { class: "results-container",
+            h1 { "Review Results" }
+
+            match &*loaded_results.read() {
+                Ok(categories) if categories.is_empty() => rsx! {
+                    p { "No reviews have been submitted yet." }
+                },
+                Ok(categories) => rsx! {
+                    for summary in categories.iter() {
+                        div { class: "category-summary", key: "{summary.category}",
+                            h2 { "{summary.category}" }
+                            div {
+                                StarRating { initial_rating: summary.average_rating, readonly: true }
+                                span { style: "margin-left: 0.5rem; color: #666;", "({summary.count} reviews)" }
+                            }
+
+                            for breakdown in summary.questions.iter() {
+                                details { class: "question-breakdown",
+                                    summary { "{breakdown.question} \u{2014} {breakdown.average_rating:.1}/5.0" }
+                                    ul {
+                                        for advice in breakdown.advice.iter() {
+                                            if !advice.trim().is_empty() {
+                                                li { "{advice}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                },
+                Err(e) => rsx! {
+                    div { class: "status-message", "Error loading results: {e}" }
+                },
+            }
+        }
+    }
+}
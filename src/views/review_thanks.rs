@@ -0,0 +1,18 @@
+use dioxus::prelude::*;
+
+use crate::Route;
+
+#[component]
+pub fn ReviewThanks(count: usize) -> Element {
+    let suffix = if count == 1 { "" } else { "s" };
+
+    rsx! {
+        document::Title { "Application Review - Thanks" }
+
+        div { class: "review-container",
+            h1 { "Thanks for your feedback!" }
+            p { "We recorded {count} review{suffix}." }
+            Link { to: Route::ReviewPage {}, class: "nav-link", "Submit more" }
+        }
+    }
+}